@@ -0,0 +1,19 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use curv::elliptic::curves::{ECPoint, ECScalar};
+use curv::elliptic::curves::sm2::{Sm2Point, Sm2Scalar};
+
+fn bench_generator_mul(c: &mut Criterion) {
+    let scalar = Sm2Scalar::random();
+
+    c.bench_function("sm2 generator_mul (comb table)", |b| {
+        b.iter(|| Sm2Point::generator_mul(&scalar))
+    });
+
+    c.bench_function("sm2 generator_mul (generic scalar_mul)", |b| {
+        b.iter(|| Sm2Point::generator().scalar_mul(&scalar))
+    });
+}
+
+criterion_group!(benches, bench_generator_mul);
+criterion_main!(benches);
@@ -21,12 +21,14 @@ use std::convert::TryFrom;
 
 use sm2::elliptic_curve::group::ff::PrimeField;
 use sm2::elliptic_curve::group::prime::PrimeCurveAffine;
+use sm2::elliptic_curve::group::Group;
 use sm2::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
 use sm2::{AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar};
 
 use generic_array::GenericArray;
 use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use zeroize::{Zeroize, Zeroizing};
 
 use crate::arithmetic::*;
@@ -41,10 +43,241 @@ lazy_static::lazy_static! {
         purpose: "generator",
         ge: AffinePoint::generator(),
     };
+
+    /// A second generator `H` with no known discrete log relative to `GENERATOR`.
+    ///
+    /// Derived from `GENERATOR` by try-and-increment: hash the compressed
+    /// generator (plus a counter) with SHA-256 and treat the digest as the
+    /// x-coordinate of a compressed point, retrying on the next counter value
+    /// whenever the candidate doesn't decompress to a point on the curve.
+    static ref BASE_POINT2: Sm2Point = {
+        let compressed_generator = GENERATOR.serialize_compressed();
+        let mut counter: u8 = 0;
+        loop {
+            let mut hasher = Sha256::new();
+            hasher.update(compressed_generator.as_slice());
+            hasher.update([counter]);
+            let digest = hasher.finalize();
+
+            let mut candidate = [0u8; 33];
+            candidate[0] = 0x02;
+            candidate[1..].copy_from_slice(&digest);
+
+            if let Ok(point) = Sm2Point::deserialize(&candidate) {
+                if !point.is_zero() {
+                    break point;
+                }
+            }
+            counter = counter.checked_add(1).expect("failed to find base_point2 candidate");
+        }
+    };
 }
 
 const GROUP_ORDER_HEX: &str = "fffffffeffffffffffffffffffffffff7203df6b21c6052b53bbf40939d54123"; // Sm2 curve
 
+// RFC 9380 hash-to-curve for Sm2 (a short-Weierstrass curve with cofactor 1).
+//
+// There is no IETF-assigned suite for Sm2, so the parameters below follow the
+// recipe of section 8 (suites for short Weierstrass curves): SHA-256 as the
+// underlying hash for `expand_message_xmd`, the simplified SWU map of section
+// 6.6.2 (curve `a` is non-zero), and no cofactor clearing since Sm2's
+// cofactor is 1.
+const FIELD_PRIME_HEX: &str = "fffffffeffffffffffffffffffffffffffffffff00000000ffffffffffffffff";
+const CURVE_B_HEX: &str = "28e9fa9e9d9f5e344d5a9e4bcf6509a7f39789f515ab8f92ddbcbd414d940e93";
+
+lazy_static::lazy_static! {
+    static ref FIELD_PRIME: BigInt = BigInt::from_hex(FIELD_PRIME_HEX).unwrap();
+    // Sm2's `a` coefficient is `p - 3`, as for the NIST P-curves.
+    static ref CURVE_A: BigInt = &*FIELD_PRIME - BigInt::from(3);
+    static ref CURVE_B: BigInt = BigInt::from_hex(CURVE_B_HEX).unwrap();
+
+    /// Non-square SSWU constant `Z`, chosen as `-ctr mod p` for the smallest
+    /// `ctr` for which `Z` is a non-square and `g(Z) = Z^3 + a*Z + b` is
+    /// non-zero, per RFC 9380 section 6.1.
+    static ref SSWU_Z: BigInt = {
+        let mut ctr = BigInt::from(1);
+        loop {
+            let z = &*FIELD_PRIME - &ctr;
+            if !is_square(&z) && !curve_equation(&z).is_zero() {
+                break z;
+            }
+            ctr += 1;
+        }
+    };
+}
+
+/// Legendre symbol check: `true` iff `a` is a nonzero square mod `FIELD_PRIME`.
+fn is_square(a: &BigInt) -> bool {
+    if a.is_zero() {
+        return false;
+    }
+    let exp = (&*FIELD_PRIME - BigInt::from(1)) / BigInt::from(2);
+    a.mod_pow(&exp, &FIELD_PRIME) == BigInt::from(1)
+}
+
+/// Modular square root for `FIELD_PRIME ≡ 3 (mod 4)`, which holds for Sm2's prime.
+fn mod_sqrt(a: &BigInt) -> BigInt {
+    let exp = (&*FIELD_PRIME + BigInt::from(1)) / BigInt::from(4);
+    a.mod_pow(&exp, &FIELD_PRIME)
+}
+
+fn mod_inv(a: &BigInt) -> BigInt {
+    a.mod_inv(&FIELD_PRIME)
+        .expect("inverting a nonzero field element must succeed")
+}
+
+fn curve_equation(x: &BigInt) -> BigInt {
+    let x3 = x.mod_mul(x, &FIELD_PRIME).mod_mul(x, &FIELD_PRIME);
+    let ax = CURVE_A.mod_mul(x, &FIELD_PRIME);
+    x3.mod_add(&ax, &FIELD_PRIME).mod_add(&CURVE_B, &FIELD_PRIME)
+}
+
+fn sgn0(a: &BigInt) -> bool {
+    a.modulus(&BigInt::from(2)) == BigInt::from(1)
+}
+
+/// `expand_message_xmd` from RFC 9380 section 5.3.1, instantiated with SHA-256.
+fn expand_message_xmd(msg: &[u8], dst: &[u8], out_len: usize) -> Vec<u8> {
+    const B_IN_BYTES: usize = 32; // SHA-256 output size
+    const S_IN_BYTES: usize = 64; // SHA-256 block size
+
+    let ell = (out_len + B_IN_BYTES - 1) / B_IN_BYTES;
+    assert!(ell <= 255, "requested hash-to-curve output is too long");
+    assert!(dst.len() <= 255, "DST must fit in a single length-prefixed byte");
+
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+    let z_pad = vec![0u8; S_IN_BYTES];
+    let l_i_b_str = (out_len as u16).to_be_bytes();
+
+    let mut msg_prime = Vec::with_capacity(z_pad.len() + msg.len() + 2 + 1 + dst_prime.len());
+    msg_prime.extend_from_slice(&z_pad);
+    msg_prime.extend_from_slice(msg);
+    msg_prime.extend_from_slice(&l_i_b_str);
+    msg_prime.push(0u8);
+    msg_prime.extend_from_slice(&dst_prime);
+
+    let b0 = Sha256::digest(&msg_prime);
+
+    let mut b_i = Sha256::new()
+        .chain_update(b0)
+        .chain_update([1u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+    uniform_bytes.extend_from_slice(&b_i);
+
+    for i in 2..=ell {
+        let xored: Vec<u8> = b0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+        b_i = Sha256::new()
+            .chain_update(xored)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(out_len);
+    uniform_bytes
+}
+
+/// `hash_to_field` (section 5.2) producing `count` field elements mod `FIELD_PRIME`.
+fn hash_to_field(msg: &[u8], dst: &[u8], count: usize) -> Vec<BigInt> {
+    // L = ceil((ceil(log2(p)) + k) / 8) with k = 128 security bits, p 256 bits.
+    const L: usize = 48;
+    let uniform_bytes = expand_message_xmd(msg, dst, count * L);
+    uniform_bytes
+        .chunks(L)
+        .map(|chunk| BigInt::from_bytes(chunk).modulus(&FIELD_PRIME))
+        .collect()
+}
+
+/// Simplified SWU map (RFC 9380 section 6.6.2) from a field element to a curve point.
+fn map_to_curve_sswu(u: &BigInt) -> Sm2Point {
+    let u2 = u.mod_mul(u, &FIELD_PRIME);
+    let z_u2 = SSWU_Z.mod_mul(&u2, &FIELD_PRIME);
+    let tv1_denom = z_u2.mod_mul(&z_u2, &FIELD_PRIME).mod_add(&z_u2, &FIELD_PRIME);
+
+    let x1 = if tv1_denom.is_zero() {
+        CURVE_B.mod_mul(&mod_inv(&SSWU_Z.mod_mul(&CURVE_A, &FIELD_PRIME)), &FIELD_PRIME)
+    } else {
+        let tv1 = mod_inv(&tv1_denom);
+        let one_plus_tv1 = BigInt::from(1).mod_add(&tv1, &FIELD_PRIME);
+        let neg_b = (&*FIELD_PRIME - &*CURVE_B).modulus(&FIELD_PRIME);
+        let neg_b_over_a = neg_b.mod_mul(&mod_inv(&CURVE_A), &FIELD_PRIME);
+        neg_b_over_a.mod_mul(&one_plus_tv1, &FIELD_PRIME)
+    };
+
+    let gx1 = curve_equation(&x1);
+    let x2 = z_u2.mod_mul(&x1, &FIELD_PRIME);
+    let gx2 = curve_equation(&x2);
+
+    let (x, y) = if is_square(&gx1) {
+        (x1, mod_sqrt(&gx1))
+    } else {
+        (x2, mod_sqrt(&gx2))
+    };
+
+    let y = if sgn0(u) != sgn0(&y) {
+        &*FIELD_PRIME - &y
+    } else {
+        y
+    };
+
+    Sm2Point::from_coords(&x, &y).expect("SSWU output must lie on the curve")
+}
+
+// Fixed-base comb table for `generator_mul`, trading one-time setup cost for
+// O(256/w) additions (and no doublings) per multiplication instead of a
+// generic double-and-add `scalar_mul`.
+const COMB_WINDOW_BITS: usize = 4;
+const COMB_WINDOWS: usize = 256 / COMB_WINDOW_BITS;
+const COMB_DIGITS: usize = (1 << COMB_WINDOW_BITS) - 1;
+
+lazy_static::lazy_static! {
+    /// `COMB_TABLE[j][d - 1] = d * 2^(w*j) * G` for `d in 1..=COMB_DIGITS`.
+    static ref COMB_TABLE: Vec<[ProjectivePoint; COMB_DIGITS]> = build_comb_table();
+}
+
+fn build_comb_table() -> Vec<[ProjectivePoint; COMB_DIGITS]> {
+    let mut table = Vec::with_capacity(COMB_WINDOWS);
+    let mut window_base = ProjectivePoint::from(GENERATOR.ge);
+    for _ in 0..COMB_WINDOWS {
+        let mut multiples = [ProjectivePoint::identity(); COMB_DIGITS];
+        multiples[0] = window_base;
+        for d in 1..COMB_DIGITS {
+            multiples[d] = multiples[d - 1] + window_base;
+        }
+        table.push(multiples);
+        for _ in 0..COMB_WINDOW_BITS {
+            window_base = window_base.double();
+        }
+    }
+    table
+}
+
+/// Evaluate `scalar * G` by selecting and summing one precomputed table entry
+/// per `COMB_WINDOW_BITS`-wide window of `scalar`, then converting to affine once.
+fn comb_generator_mul(scalar: &Sm2Scalar) -> Sm2Point {
+    let bytes = scalar.fe.to_bytes();
+    let mut acc = ProjectivePoint::identity();
+    for window in 0..COMB_WINDOWS {
+        let byte_index = 31 - window / 2;
+        let nibble = if window % 2 == 0 {
+            bytes[byte_index] & 0x0f
+        } else {
+            bytes[byte_index] >> 4
+        };
+        if nibble != 0 {
+            acc += COMB_TABLE[window][(nibble - 1) as usize];
+        }
+    }
+    Sm2Point {
+        purpose: "generator_mul",
+        ge: acc.to_affine(),
+    }
+}
+
 /// Sm2 curve implementation based on [sm2] library
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Sm2 {}
@@ -224,6 +457,31 @@ impl PartialEq for Sm2Scalar {
     }
 }
 
+impl Sm2Scalar {
+    /// Hash an arbitrary message to a scalar, per RFC 9380 section 5.2 `hash_to_field`
+    /// with `count = 1`, reduced modulo the Sm2 group order.
+    pub fn hash_to_scalar(msg: &[u8], dst: &[u8]) -> Sm2Scalar {
+        const L: usize = 48;
+        let uniform_bytes = expand_message_xmd(msg, dst, L);
+        let n = BigInt::from_bytes(&uniform_bytes);
+        Sm2Scalar::from_bigint(&n)
+    }
+
+    /// Reduce a 64-byte big-endian integer modulo the Sm2 group order.
+    ///
+    /// Unlike reducing a 256-bit hash directly, a wide 512-bit input leaves no
+    /// detectable bias after reduction, which matters for unbiased scalar
+    /// sampling and for Fiat-Shamir challenges.
+    pub fn from_bytes_mod_order_wide(bytes: &[u8; 64]) -> Sm2Scalar {
+        Sm2Scalar::from_bigint_wide(&BigInt::from_bytes(bytes))
+    }
+
+    /// Reduce an arbitrary-width big integer modulo the Sm2 group order.
+    pub fn from_bigint_wide(n: &BigInt) -> Sm2Scalar {
+        Sm2Scalar::from_bigint(n)
+    }
+}
+
 impl ECPoint for Sm2Point {
     type Scalar = Sm2Scalar;
     type Underlying = PK;
@@ -247,7 +505,7 @@ impl ECPoint for Sm2Point {
     }
 
     fn base_point2() -> &'static Sm2Point {
-        &GENERATOR
+        &BASE_POINT2
     }
 
     fn from_coords(x: &BigInt, y: &BigInt) -> Result<Sm2Point, NotOnCurve> {
@@ -314,10 +572,14 @@ impl ECPoint for Sm2Point {
             let encoded = EncodedPoint::from_bytes(bytes).map_err(|_| DeserializationError)?;
             let affine_point = AffinePoint::from_encoded_point(&encoded);
 
-            Ok(Sm2Point {
-                purpose: "from_bytes",
-                ge: affine_point.unwrap(),
-            })
+            if bool::from(affine_point.is_some()) {
+                Ok(Sm2Point {
+                    purpose: "from_bytes",
+                    ge: affine_point.unwrap(),
+                })
+            } else {
+                Err(DeserializationError)
+            }
         }
     }
 
@@ -334,10 +596,7 @@ impl ECPoint for Sm2Point {
     }
 
     fn generator_mul(scalar: &Self::Scalar) -> Self {
-        Sm2Point {
-            purpose: "generator_mul",
-            ge: Sm2Point::generator().scalar_mul(scalar).ge,
-        }
+        comb_generator_mul(scalar)
     }
 
     fn add_point(&self, other: &Self) -> Self {
@@ -386,8 +645,321 @@ impl PartialEq for Sm2Point {
     }
 }
 
+impl Sm2Point {
+    /// Hash an arbitrary message to a curve point with unknown discrete log,
+    /// per RFC 9380: `hash_to_field` (count = 2) followed by the simplified
+    /// SWU map and point addition. Sm2's cofactor is 1 so no clearing is needed.
+    pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Sm2Point {
+        let u = hash_to_field(msg, dst, 2);
+        let q0 = map_to_curve_sswu(&u[0]);
+        let q1 = map_to_curve_sswu(&u[1]);
+        q0.add_point(&q1)
+    }
+
+    /// Variable-base multi-scalar multiplication: `Σ scalars[i] * points[i]`.
+    ///
+    /// Uses windowed Straus for small inputs and switches to bucket-based
+    /// Pippenger once the number of terms makes per-point bucket overhead pay
+    /// for itself. Both paths accumulate in projective coordinates and
+    /// convert to affine once at the end.
+    pub fn multiscalar_mul(
+        scalars: &[Sm2Scalar],
+        points: &[Sm2Point],
+    ) -> Result<Sm2Point, MismatchedMultiscalarLengths> {
+        if scalars.len() != points.len() {
+            return Err(MismatchedMultiscalarLengths {
+                scalars: scalars.len(),
+                points: points.len(),
+            });
+        }
+        if scalars.is_empty() {
+            return Ok(Sm2Point::zero());
+        }
+
+        let ge = if scalars.len() <= PIPPENGER_THRESHOLD {
+            straus_multiscalar_mul(scalars, points)
+        } else {
+            pippenger_multiscalar_mul(scalars, points)
+        };
+
+        Ok(Sm2Point {
+            purpose: "multiscalar_mul",
+            ge,
+        })
+    }
+}
+
+/// Error returned by [`Sm2Point::multiscalar_mul`] when the scalar and point
+/// slices have different lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MismatchedMultiscalarLengths {
+    pub scalars: usize,
+    pub points: usize,
+}
+
+impl std::fmt::Display for MismatchedMultiscalarLengths {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "multiscalar_mul requires equally-sized slices (got {} scalars, {} points)",
+            self.scalars, self.points
+        )
+    }
+}
+
+impl std::error::Error for MismatchedMultiscalarLengths {}
+
+/// Switch-over point from Straus to Pippenger, chosen so Pippenger's
+/// per-window bucket-reduction overhead is only paid once it's amortized
+/// over enough terms.
+const PIPPENGER_THRESHOLD: usize = 32;
+const STRAUS_WINDOW_BITS: usize = 4;
+const PIPPENGER_WINDOW_BITS: usize = 6;
+
+/// The `window`-th `width`-bit digit of `bytes`, a 32-byte big-endian integer,
+/// counting windows from the least-significant bit.
+fn scalar_window_bits(bytes: &[u8], window: usize, width: usize) -> u32 {
+    let mut value: u32 = 0;
+    for i in 0..width {
+        let bit_index = window * width + i;
+        if bit_index >= bytes.len() * 8 {
+            break;
+        }
+        let byte = bytes[bytes.len() - 1 - bit_index / 8];
+        let bit = (byte >> (bit_index % 8)) & 1;
+        value |= u32::from(bit) << i;
+    }
+    value
+}
+
+fn straus_multiscalar_mul(scalars: &[Sm2Scalar], points: &[Sm2Point]) -> PK {
+    let table_size = 1usize << STRAUS_WINDOW_BITS;
+    let tables: Vec<Vec<ProjectivePoint>> = points
+        .iter()
+        .map(|point| {
+            let base = ProjectivePoint::from(point.ge);
+            let mut table = Vec::with_capacity(table_size);
+            table.push(ProjectivePoint::identity());
+            for d in 1..table_size {
+                table.push(table[d - 1] + base);
+            }
+            table
+        })
+        .collect();
+    let scalar_bytes: Vec<_> = scalars.iter().map(|s| s.fe.to_bytes()).collect();
+
+    let num_windows = (256 + STRAUS_WINDOW_BITS - 1) / STRAUS_WINDOW_BITS;
+    let mut acc = ProjectivePoint::identity();
+    for window in (0..num_windows).rev() {
+        for _ in 0..STRAUS_WINDOW_BITS {
+            acc = acc.double();
+        }
+        for (table, bytes) in tables.iter().zip(scalar_bytes.iter()) {
+            let digit = scalar_window_bits(bytes.as_slice(), window, STRAUS_WINDOW_BITS) as usize;
+            if digit != 0 {
+                acc = acc + table[digit];
+            }
+        }
+    }
+    acc.to_affine()
+}
+
+fn pippenger_multiscalar_mul(scalars: &[Sm2Scalar], points: &[Sm2Point]) -> PK {
+    let c = PIPPENGER_WINDOW_BITS;
+    let num_buckets = (1usize << c) - 1;
+    let num_windows = (256 + c - 1) / c;
+    let scalar_bytes: Vec<_> = scalars.iter().map(|s| s.fe.to_bytes()).collect();
+
+    let mut result = ProjectivePoint::identity();
+    for window in (0..num_windows).rev() {
+        for _ in 0..c {
+            result = result.double();
+        }
+
+        let mut buckets = vec![ProjectivePoint::identity(); num_buckets];
+        for (bytes, point) in scalar_bytes.iter().zip(points) {
+            let digit = scalar_window_bits(bytes.as_slice(), window, c) as usize;
+            if digit != 0 {
+                buckets[digit - 1] = buckets[digit - 1] + ProjectivePoint::from(point.ge);
+            }
+        }
+
+        // Running-sum trick: sum_{k=1}^{n} k*bucket_k in one backward pass.
+        let mut running_sum = ProjectivePoint::identity();
+        let mut window_sum = ProjectivePoint::identity();
+        for bucket in buckets.into_iter().rev() {
+            running_sum = running_sum + bucket;
+            window_sum = window_sum + running_sum;
+        }
+        result = result + window_sum;
+    }
+    result.to_affine()
+}
+
 impl Zeroize for Sm2Point {
     fn zeroize(&mut self) {
         self.ge.zeroize()
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_point2_is_independent_of_generator() {
+        let g = Sm2Point::generator();
+        let h = Sm2Point::base_point2();
+
+        assert_ne!(g, h);
+        assert!(!g.is_zero());
+        assert!(!h.is_zero());
+        assert!(g.check_point_order_equals_group_order());
+        assert!(h.check_point_order_equals_group_order());
+    }
+
+    #[test]
+    fn hash_to_curve_is_deterministic_and_nonzero() {
+        let p1 = Sm2Point::hash_to_curve(b"hello world", b"CURV-SM2_XMD:SHA-256_SSWU_RO_");
+        let p2 = Sm2Point::hash_to_curve(b"hello world", b"CURV-SM2_XMD:SHA-256_SSWU_RO_");
+        assert_eq!(p1, p2);
+        assert!(!p1.is_zero());
+        assert!(p1.check_point_order_equals_group_order());
+    }
+
+    #[test]
+    fn hash_to_curve_varies_with_input() {
+        let dst = b"CURV-SM2_XMD:SHA-256_SSWU_RO_";
+        let p1 = Sm2Point::hash_to_curve(b"message one", dst);
+        let p2 = Sm2Point::hash_to_curve(b"message two", dst);
+        let p3 = Sm2Point::hash_to_curve(b"message one", b"CURV-SM2_XMD:SHA-256_SSWU_RO_v2");
+        assert_ne!(p1, p2);
+        assert_ne!(p1, p3);
+    }
+
+    #[test]
+    fn hash_to_scalar_is_deterministic_and_nonzero() {
+        let dst = b"CURV-SM2_XMD:SHA-256_RO_";
+        let s1 = Sm2Scalar::hash_to_scalar(b"hello world", dst);
+        let s2 = Sm2Scalar::hash_to_scalar(b"hello world", dst);
+        assert_eq!(s1, s2);
+        assert!(!s1.is_zero());
+    }
+
+    #[test]
+    fn hash_to_scalar_varies_with_input() {
+        let dst = b"CURV-SM2_XMD:SHA-256_RO_";
+        let s1 = Sm2Scalar::hash_to_scalar(b"message one", dst);
+        let s2 = Sm2Scalar::hash_to_scalar(b"message two", dst);
+        assert_ne!(s1, s2);
+    }
+
+    #[test]
+    fn generator_mul_matches_scalar_mul_on_generator() {
+        let edge_cases = [
+            Sm2Scalar::zero(),
+            Sm2Scalar::from_bigint(&BigInt::from(1)),
+            Sm2Scalar::from_bigint(&(Sm2Scalar::group_order() - BigInt::from(1))),
+        ];
+
+        for scalar in edge_cases {
+            let via_table = Sm2Point::generator_mul(&scalar);
+            let via_scalar_mul = Sm2Point::generator().scalar_mul(&scalar);
+            assert_eq!(via_table, via_scalar_mul);
+        }
+
+        for _ in 0..32 {
+            let scalar = Sm2Scalar::random();
+            let via_table = Sm2Point::generator_mul(&scalar);
+            let via_scalar_mul = Sm2Point::generator().scalar_mul(&scalar);
+            assert_eq!(via_table, via_scalar_mul);
+        }
+    }
+
+    #[test]
+    fn from_bytes_mod_order_wide_matches_bigint_modulus() {
+        let mut rng = thread_rng();
+        for _ in 0..32 {
+            let mut wide = [0u8; 64];
+            rng.fill(&mut wide[..]);
+
+            let expected = BigInt::from_bytes(&wide).modulus(Sm2Scalar::group_order());
+            let scalar = Sm2Scalar::from_bytes_mod_order_wide(&wide);
+            assert_eq!(scalar.to_bigint(), expected);
+        }
+    }
+
+    #[test]
+    fn from_bytes_mod_order_wide_never_rejects() {
+        assert_eq!(
+            Sm2Scalar::from_bytes_mod_order_wide(&[0u8; 64]).to_bigint(),
+            BigInt::from(0)
+        );
+        assert_eq!(
+            Sm2Scalar::from_bytes_mod_order_wide(&[0xff; 64]).to_bigint(),
+            BigInt::from_bytes(&[0xff; 64]).modulus(Sm2Scalar::group_order())
+        );
+        for _ in 0..64 {
+            let mut wide = [0u8; 64];
+            thread_rng().fill(&mut wide[..]);
+            // Must always produce a valid scalar, never an error/panic.
+            let _ = Sm2Scalar::from_bytes_mod_order_wide(&wide);
+        }
+    }
+
+    fn naive_multiscalar_mul(scalars: &[Sm2Scalar], points: &[Sm2Point]) -> Sm2Point {
+        scalars
+            .iter()
+            .zip(points)
+            .fold(Sm2Point::zero(), |acc, (s, p)| acc.add_point(&p.scalar_mul(s)))
+    }
+
+    #[test]
+    fn multiscalar_mul_matches_naive_sum_straus_path() {
+        // n <= PIPPENGER_THRESHOLD exercises the Straus path.
+        let n = 8;
+        let scalars: Vec<_> = (0..n).map(|_| Sm2Scalar::random()).collect();
+        let points: Vec<_> = (0..n)
+            .map(|_| Sm2Point::generator_mul(&Sm2Scalar::random()))
+            .collect();
+
+        let expected = naive_multiscalar_mul(&scalars, &points);
+        let actual = Sm2Point::multiscalar_mul(&scalars, &points).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn multiscalar_mul_matches_naive_sum_pippenger_path() {
+        // n > PIPPENGER_THRESHOLD exercises the Pippenger path.
+        let n = PIPPENGER_THRESHOLD + 5;
+        let scalars: Vec<_> = (0..n).map(|_| Sm2Scalar::random()).collect();
+        let points: Vec<_> = (0..n)
+            .map(|_| Sm2Point::generator_mul(&Sm2Scalar::random()))
+            .collect();
+
+        let expected = naive_multiscalar_mul(&scalars, &points);
+        let actual = Sm2Point::multiscalar_mul(&scalars, &points).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn multiscalar_mul_empty_input_is_zero() {
+        let result = Sm2Point::multiscalar_mul(&[], &[]).unwrap();
+        assert!(result.is_zero());
+    }
+
+    #[test]
+    fn multiscalar_mul_rejects_mismatched_lengths() {
+        let scalars = vec![Sm2Scalar::random(), Sm2Scalar::random()];
+        let points = vec![*Sm2Point::generator()];
+
+        let err = Sm2Point::multiscalar_mul(&scalars, &points).unwrap_err();
+        assert_eq!(
+            err,
+            MismatchedMultiscalarLengths {
+                scalars: 2,
+                points: 1,
+            }
+        );
+    }
 }
\ No newline at end of file